@@ -206,3 +206,501 @@ where
 
     μ
 }
+
+/// Find every distinct minimum-cost path from `start` to `goal`.
+///
+/// Runs a standard Dijkstra from `start`, but instead of keeping a single
+/// predecessor per node it keeps the *set* of predecessors that achieve the
+/// optimal distance. Once `goal`'s distance is final, it backtracks over
+/// those predecessor sets to enumerate every shortest path.
+///
+/// Returns each path as a `Vec<G::NodeId>` ordered from `start` to `goal`.
+/// If `start == goal`, the single one-node path is returned. If `goal` is
+/// unreachable from `start`, an empty vector is returned.
+pub fn all_shortest_paths<G, F, K>(
+    graph: G,
+    start: G::NodeId,
+    goal: G::NodeId,
+    mut edge_cost: F,
+) -> Vec<Vec<G::NodeId>>
+where
+    G: IntoEdgesDirected + Visitable,
+    G::NodeId: Eq + Hash,
+    F: FnMut(G::EdgeRef) -> K,
+    K: PositiveMeasure + Copy + std::cmp::Ord,
+{
+    if start == goal {
+        return vec![vec![start]];
+    }
+
+    let zero_score = K::default();
+
+    let mut scores = HashMap::new();
+    scores.insert(start, zero_score);
+
+    let mut preds: HashMap<G::NodeId, Vec<G::NodeId>> = HashMap::new();
+
+    let mut visited = graph.visit_map();
+    let mut visit_next = BinaryHeap::new();
+    visit_next.push(MinScored(zero_score, start));
+
+    while let Some(MinScored(node_score, node)) = visit_next.pop() {
+        if visited.is_visited(&node) {
+            continue;
+        }
+
+        // Zero-cost edges can tie a node's distance with `goal`'s, so we
+        // must keep relaxing (including into already-visited nodes, to
+        // pick up extra predecessors on ties) until the heap only holds
+        // nodes that are strictly farther than `goal`'s settled distance.
+        if let Some(&goal_score) = scores.get(&goal) {
+            if node_score > goal_score {
+                break;
+            }
+        }
+
+        for edge in graph.edges_directed(node, Outgoing) {
+            let next = edge.target();
+            let next_score = node_score + edge_cost(edge);
+            match scores.entry(next) {
+                Occupied(ent) => {
+                    if next_score < *ent.get() {
+                        *ent.into_mut() = next_score;
+                        preds.insert(next, vec![node]);
+                        if !visited.is_visited(&next) {
+                            visit_next.push(MinScored(next_score, next));
+                        }
+                    } else if next_score == *ent.get() {
+                        let next_preds = preds.entry(next).or_insert_with(Vec::new);
+                        if !next_preds.contains(&node) {
+                            next_preds.push(node);
+                        }
+                    }
+                }
+                Vacant(ent) => {
+                    ent.insert(next_score);
+                    preds.insert(next, vec![node]);
+                    visit_next.push(MinScored(next_score, next));
+                }
+            }
+        }
+        visited.visit(node);
+    }
+
+    if !scores.contains_key(&goal) {
+        return Vec::new();
+    }
+
+    let mut paths = Vec::new();
+    let mut path = vec![goal];
+    backtrack_paths(goal, start, &preds, &mut path, &mut paths);
+    paths
+}
+
+/// Walk `preds` backwards from `node` to `start`, emitting one path per
+/// distinct root-to-`node` chain.
+fn backtrack_paths<N>(
+    node: N,
+    start: N,
+    preds: &HashMap<N, Vec<N>>,
+    path: &mut Vec<N>,
+    paths: &mut Vec<Vec<N>>,
+) where
+    N: Eq + Hash + Copy,
+{
+    if node == start {
+        let mut complete = path.clone();
+        complete.reverse();
+        paths.push(complete);
+        return;
+    }
+    if let Some(predecessors) = preds.get(&node) {
+        for &pred in predecessors {
+            // Zero-cost cycles make the predecessor sets cyclic; only
+            // simple chains are followed.
+            if path.contains(&pred) {
+                continue;
+            }
+            path.push(pred);
+            backtrack_paths(pred, start, preds, path, paths);
+            path.pop();
+        }
+    }
+}
+
+/// Bidirectional A*, generalizing [`bidirectional_dijkstra`] with two
+/// admissible heuristics.
+///
+/// `h_forward` estimates the remaining cost from a node to `goal`, and
+/// `h_backward` estimates the remaining cost from a node back to `start`.
+/// Individually consistent heuristics are *not* sufficient for
+/// correctness here: combining two independently-derived potentials can
+/// silently return a suboptimal cost, because the μ-convergence proof
+/// that `bidirectional_dijkstra`'s termination test relies on only holds
+/// when both heuristics come from a single shared potential. Concretely,
+/// `h_forward(v) + h_backward(v)` must be the *same* constant for every
+/// node `v` reachable from `start` or able to reach `goal` (for example,
+/// derive `h_backward` from `h_forward` as `h_backward(v) =
+/// h_forward(start) - h_forward(v)`, which keeps the sum pinned at
+/// `h_forward(start)` for every `v`). This invariant is checked with
+/// `debug_assert!` at every node visited by either frontier (so debug and
+/// test builds panic on a violation instead of silently returning a
+/// suboptimal cost), but the checks and the extra heuristic evaluations
+/// they require are compiled out of release builds. Passing `|_|
+/// K::default()` for both recovers the behavior of `bidirectional_dijkstra`.
+///
+/// Internally, each direction searches over reduced edge costs obtained by
+/// shifting `edge_cost` by the potentials: `edge_cost(e) - h_forward(u) +
+/// h_forward(v)` for the forward search, and symmetrically with
+/// `h_backward` for the backward search. Each `BinaryHeap` is ordered by
+/// `g + h` rather than raw `g`, and the best meeting cost `μ` is tracked
+/// exactly as in `bidirectional_dijkstra`.
+pub fn bidirectional_astar<G, F, HF, HB, K>(
+    graph: G,
+    start: G::NodeId,
+    goal: G::NodeId,
+    mut edge_cost: F,
+    mut h_forward: HF,
+    mut h_backward: HB,
+) -> K
+where
+    G: IntoEdgesDirected + Visitable,
+    G::NodeId: Eq + Hash + Copy,
+    F: FnMut(G::EdgeRef) -> K,
+    HF: FnMut(G::NodeId) -> K,
+    HB: FnMut(G::NodeId) -> K,
+    K: PositiveMeasure + Copy + std::cmp::Ord,
+{
+    if start == goal {
+        return K::default();
+    }
+
+    let mut μ: K = PositiveMeasure::max();
+    // Until the frontiers meet, `μ` stays at this value and the termination
+    // test is skipped (adding `potential_offset` to it could overflow).
+    let no_meeting: K = PositiveMeasure::max();
+
+    let zero_score = K::default();
+
+    // The sum `h_forward(v) + h_backward(v)` must be the same constant for
+    // every node `v` (both heuristics must come from a single shared
+    // potential); measuring it at `start` pins that constant down, since
+    // `h_backward(start)` is the estimated cost of a zero-length trip back
+    // to `start`. Checked with `debug_assert!` rather than `assert!`: the
+    // per-node checks below would otherwise force the "other" heuristic to
+    // be evaluated at every node visited by either frontier, even though
+    // only one direction ever needs it there, doubling heuristic calls in
+    // release builds too.
+    let potential_offset = h_forward(start) + h_backward(start);
+    debug_assert!(
+        potential_offset == h_forward(goal) + h_backward(goal),
+        "bidirectional_astar: h_forward and h_backward must come from a single shared \
+         potential, i.e. h_forward(v) + h_backward(v) must be the same constant for every v"
+    );
+
+    let mut scores = HashMap::new();
+    scores.insert((start, start), zero_score);
+    scores.insert((goal, goal), zero_score);
+
+    let mut us = start;
+    let mut f_closed = graph.visit_map();
+    f_closed.visit(us);
+    let mut f_edge_iter = graph.edges_directed(us, Outgoing);
+
+    let mut ut = goal;
+    let mut b_closed = graph.visit_map();
+    b_closed.visit(ut);
+    let mut b_edge_iter = graph.edges_directed(ut, Incoming);
+
+    let mut f_visit_next = BinaryHeap::new();
+    f_visit_next.push(MinScored(h_forward(start), start));
+
+    let mut b_visit_next = BinaryHeap::new();
+    b_visit_next.push(MinScored(h_backward(goal), goal));
+
+    let mut f_top_key = h_forward(start);
+    let mut b_top_key = h_backward(goal);
+
+    'outer: loop {
+        'forward: loop {
+            if μ != no_meeting && f_top_key + b_top_key >= μ + potential_offset {
+                break 'outer;
+            }
+            if let Some(edge) = f_edge_iter.next() {
+                // relax
+                let next = edge.target();
+                if f_closed.is_visited(&next) {
+                    break 'forward;
+                }
+                let h_forward_next = h_forward(next);
+                debug_assert!(
+                    h_forward_next + h_backward(next) == potential_offset,
+                    "bidirectional_astar: h_forward and h_backward must come from a single \
+                     shared potential, i.e. h_forward(v) + h_backward(v) must be the same \
+                     constant for every v"
+                );
+                let next_score = scores[&(start, us)] + edge_cost(edge);
+                let next_key = next_score + h_forward_next;
+                match scores.entry((start, next)) {
+                    Occupied(ent) => {
+                        if next_score < *ent.get() {
+                            *ent.into_mut() = next_score;
+                            f_visit_next.push(MinScored(next_key, next));
+                        }
+                    }
+                    Vacant(ent) => {
+                        ent.insert(next_score);
+                        f_visit_next.push(MinScored(next_key, next));
+                    }
+                }
+                if b_closed.is_visited(&next) {
+                    μ = min(
+                        μ,
+                        scores[&(start, us)] + edge_cost(edge) + scores[&(next, goal)],
+                    );
+                }
+            } else {
+                loop {
+                    match f_visit_next.pop() {
+                        Some(MinScored(node_key, node)) => {
+                            if !f_closed.is_visited(&node) {
+                                f_closed.visit(node);
+                                us = node;
+                                f_top_key = node_key;
+                                f_edge_iter = graph.edges_directed(us, Outgoing);
+                                continue 'forward;
+                            }
+                        }
+                        None => break 'outer,
+                    }
+                }
+            }
+        }
+
+        'backward: loop {
+            if μ != no_meeting && f_top_key + b_top_key >= μ + potential_offset {
+                break 'outer;
+            }
+            if let Some(edge) = b_edge_iter.next() {
+                // relax
+                let next = edge.source();
+                if b_closed.is_visited(&next) {
+                    break 'backward;
+                }
+                let h_backward_next = h_backward(next);
+                debug_assert!(
+                    h_forward(next) + h_backward_next == potential_offset,
+                    "bidirectional_astar: h_forward and h_backward must come from a single \
+                     shared potential, i.e. h_forward(v) + h_backward(v) must be the same \
+                     constant for every v"
+                );
+                let next_score = scores[&(ut, goal)] + edge_cost(edge);
+                let next_key = next_score + h_backward_next;
+                match scores.entry((next, goal)) {
+                    Occupied(ent) => {
+                        if next_score < *ent.get() {
+                            *ent.into_mut() = next_score;
+                            b_visit_next.push(MinScored(next_key, next));
+                        }
+                    }
+                    Vacant(ent) => {
+                        ent.insert(next_score);
+                        b_visit_next.push(MinScored(next_key, next));
+                    }
+                }
+                if f_closed.is_visited(&next) {
+                    μ = min(
+                        μ,
+                        scores[&(start, next)] + edge_cost(edge) + scores[&(ut, goal)],
+                    );
+                }
+            } else {
+                loop {
+                    match b_visit_next.pop() {
+                        Some(MinScored(node_key, node)) => {
+                            if !b_closed.is_visited(&node) {
+                                b_closed.visit(node);
+                                ut = node;
+                                b_top_key = node_key;
+                                b_edge_iter = graph.edges_directed(ut, Incoming);
+                                continue 'backward;
+                            }
+                        }
+                        None => break 'outer,
+                    }
+                }
+            }
+        }
+    }
+
+    μ
+}
+
+/// Like [`bidirectional_dijkstra`], but also reconstructs the shortest path.
+///
+/// Returns `Some((cost, path))` where `path` runs from `start` to `goal`
+/// inclusive, or `None` if `goal` is unreachable from `start`.
+pub fn bidirectional_dijkstra_path<G, F, K>(
+    graph: G,
+    start: G::NodeId,
+    goal: G::NodeId,
+    mut edge_cost: F,
+) -> Option<(K, Vec<G::NodeId>)>
+where
+    G: IntoEdgesDirected + Visitable,
+    G::NodeId: Eq + Hash,
+    F: FnMut(G::EdgeRef) -> K,
+    K: PositiveMeasure + Copy + std::cmp::Ord,
+{
+    if start == goal {
+        return Some((K::default(), vec![start]));
+    }
+
+    let mut μ: K = PositiveMeasure::max();
+    let mut meeting_node: Option<G::NodeId> = None;
+
+    let zero_score = K::default();
+
+    let mut scores = HashMap::new();
+    scores.insert((start, start), zero_score);
+    scores.insert((goal, goal), zero_score);
+
+    // Predecessor of a node on the path from `start` (forward) or towards
+    // `goal` (backward).
+    let mut f_predecessor: HashMap<G::NodeId, G::NodeId> = HashMap::new();
+    let mut b_predecessor: HashMap<G::NodeId, G::NodeId> = HashMap::new();
+
+    let mut us = start;
+    let mut f_closed = graph.visit_map();
+    f_closed.visit(us);
+    let mut f_edge_iter = graph.edges_directed(us, Outgoing);
+
+    let mut ut = goal;
+    let mut b_closed = graph.visit_map();
+    b_closed.visit(ut);
+    let mut b_edge_iter = graph.edges_directed(ut, Incoming);
+
+    let mut f_visit_next = BinaryHeap::new();
+    f_visit_next.push(MinScored(zero_score, start));
+
+    let mut b_visit_next = BinaryHeap::new();
+    b_visit_next.push(MinScored(zero_score, goal));
+
+    'outer: loop {
+        'forward: loop {
+            if scores[&(start, us)] + scores[&(ut, goal)] >= μ {
+                break 'outer;
+            }
+            if let Some(edge) = f_edge_iter.next() {
+                // relax
+                let next = edge.target();
+                if f_closed.is_visited(&next) {
+                    break 'forward;
+                }
+                let next_score = scores[&(start, us)] + edge_cost(edge);
+                match scores.entry((start, next)) {
+                    Occupied(ent) => {
+                        if next_score < *ent.get() {
+                            *ent.into_mut() = next_score;
+                            f_visit_next.push(MinScored(next_score, next));
+                            f_predecessor.insert(next, us);
+                        }
+                    }
+                    Vacant(ent) => {
+                        ent.insert(next_score);
+                        f_visit_next.push(MinScored(next_score, next));
+                        f_predecessor.insert(next, us);
+                    }
+                }
+                if b_closed.is_visited(&next) {
+                    let candidate = scores[&(start, us)] + edge_cost(edge) + scores[&(next, goal)];
+                    if candidate < μ {
+                        μ = candidate;
+                        meeting_node = Some(next);
+                    }
+                }
+            } else {
+                loop {
+                    match f_visit_next.pop() {
+                        Some(MinScored(_node_score, node)) => {
+                            if !f_closed.is_visited(&node) {
+                                f_closed.visit(node);
+                                us = node;
+                                f_edge_iter = graph.edges_directed(us, Outgoing);
+                                continue 'forward;
+                            }
+                        }
+                        None => break 'outer,
+                    }
+                }
+            }
+        }
+
+        'backward: loop {
+            if scores[&(start, us)] + scores[&(ut, goal)] >= μ {
+                break 'outer;
+            }
+            if let Some(edge) = b_edge_iter.next() {
+                // relax
+                let next = edge.source();
+                if b_closed.is_visited(&next) {
+                    break 'backward;
+                }
+                let next_score = scores[&(ut, goal)] + edge_cost(edge);
+                match scores.entry((next, goal)) {
+                    Occupied(ent) => {
+                        if next_score < *ent.get() {
+                            *ent.into_mut() = next_score;
+                            b_visit_next.push(MinScored(next_score, next));
+                            b_predecessor.insert(next, ut);
+                        }
+                    }
+                    Vacant(ent) => {
+                        ent.insert(next_score);
+                        b_visit_next.push(MinScored(next_score, next));
+                        b_predecessor.insert(next, ut);
+                    }
+                }
+                if f_closed.is_visited(&next) {
+                    let candidate = scores[&(start, next)] + edge_cost(edge) + scores[&(ut, goal)];
+                    if candidate < μ {
+                        μ = candidate;
+                        meeting_node = Some(next);
+                    }
+                }
+            } else {
+                loop {
+                    match b_visit_next.pop() {
+                        Some(MinScored(_node_score, node)) => {
+                            if !b_closed.is_visited(&node) {
+                                b_closed.visit(node);
+                                ut = node;
+                                b_edge_iter = graph.edges_directed(ut, Incoming);
+                                continue 'backward;
+                            }
+                        }
+                        None => break 'outer,
+                    }
+                }
+            }
+        }
+    }
+
+    let meet = meeting_node?;
+
+    let mut path = vec![meet];
+    let mut node = meet;
+    while node != start {
+        node = f_predecessor[&node];
+        path.push(node);
+    }
+    path.reverse();
+
+    let mut node = meet;
+    while node != goal {
+        node = b_predecessor[&node];
+        path.push(node);
+    }
+
+    Some((μ, path))
+}
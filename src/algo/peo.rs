@@ -2,48 +2,122 @@ use crate::algo::IntoNodeIdentifiers;
 use crate::algo::NodeIndexable;
 use crate::algo::Visitable;
 use crate::visit::IntoEdges;
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet};
 use std::hash::Hash;
 
-/// try to find a peo
+/// Maximum Cardinality Search ordering of `graph`.
+///
+/// Repeatedly picks the unvisited node with the most already-visited
+/// neighbors, appends it to the returned order, and bumps the weight of
+/// its unvisited neighbors. Ties are broken arbitrarily. Runs in O(n+m)
+/// using bucket queues keyed by weight.
+///
 /// The input graph is treated as if undirected.
-pub fn peo<G>(graph: &G) -> Option<Vec<G::NodeId>>
+pub fn mcs_order<G>(graph: &G) -> Vec<G::NodeId>
 where
     G: Visitable + NodeIndexable + IntoNodeIdentifiers + IntoEdges,
-    G::NodeId: Hash + Eq,
+    G::NodeId: Hash + Eq + Copy,
 {
-    let mut v: Vec<G::NodeId> = vec![];
-    let mut nodes: HashSet<G::NodeId> = graph.node_identifiers().collect();
-
-    'outer: while !nodes.is_empty() {
-        for a in nodes.iter().copied() {
-            if is_clique(graph, graph.neighbors(a).collect()) {
-                v.push(a);
-                nodes.remove(&a);
-                continue 'outer;
+    let nodes: Vec<G::NodeId> = graph.node_identifiers().collect();
+    let n = nodes.len();
+    if n == 0 {
+        return Vec::new();
+    }
+
+    let mut weight: HashMap<G::NodeId, usize> = nodes.iter().map(|&v| (v, 0)).collect();
+    let mut visited: HashSet<G::NodeId> = HashSet::with_capacity(n);
+
+    // `buckets[w]` holds nodes that were pushed with weight `w`; an entry
+    // becomes stale once the node's weight grows again or it gets
+    // visited, and is skipped lazily when popped.
+    let mut buckets: Vec<Vec<G::NodeId>> = vec![Vec::new(); n];
+    for &v in &nodes {
+        buckets[0].push(v);
+    }
+
+    let mut max_weight = 0usize;
+    let mut order = Vec::with_capacity(n);
+
+    while order.len() < n {
+        while max_weight > 0 && buckets[max_weight].is_empty() {
+            max_weight -= 1;
+        }
+
+        let v = match buckets[max_weight].pop() {
+            Some(candidate)
+                if !visited.contains(&candidate) && weight[&candidate] == max_weight =>
+            {
+                candidate
             }
+            _ => continue,
+        };
+
+        visited.insert(v);
+        order.push(v);
+
+        // Dedupe in case of parallel edges: each distinct unvisited
+        // neighbor must gain exactly one unit of weight from visiting `v`.
+        let distinct_neighbors: HashSet<G::NodeId> = graph
+            .neighbors(v)
+            .filter(|u| !visited.contains(u))
+            .collect();
+        for u in distinct_neighbors {
+            let w = weight.get_mut(&u).expect("every node has a weight entry");
+            *w += 1;
+            if *w >= buckets.len() {
+                buckets.resize(*w + 1, Vec::new());
+            }
+            if *w > max_weight {
+                max_weight = *w;
+            }
+            buckets[*w].push(u);
         }
-        return None;
     }
 
-    Some(v)
+    order
 }
 
+/// Try to find a perfect elimination ordering (PEO) of `graph`.
+///
+/// Builds a candidate ordering from [`mcs_order`] (reversed) in O(n+m),
+/// then verifies it is indeed a PEO in a separate O(n+m) pass: for each
+/// vertex `v`, let `u` be its closest later neighbor in the ordering;
+/// `v`'s ordering is only valid if every other later neighbor of `v` is
+/// also a neighbor of `u`. Returns `None` when the graph is not chordal.
+///
 /// The input graph is treated as if undirected.
-fn is_clique<G>(graph: G, nodes: HashSet<G::NodeId>) -> bool
+pub fn peo<G>(graph: &G) -> Option<Vec<G::NodeId>>
 where
     G: Visitable + NodeIndexable + IntoNodeIdentifiers + IntoEdges,
-    G::NodeId: Hash + Eq,
+    G::NodeId: Hash + Eq + Copy,
 {
-    for a in &nodes {
-        let mut y = nodes.clone();
-        y.remove(a);
-        for b in graph.neighbors(*a) {
-            y.remove(&b);
+    let mut sigma = mcs_order(graph);
+    sigma.reverse();
+
+    let pos: HashMap<G::NodeId, usize> = sigma.iter().enumerate().map(|(i, &v)| (v, i)).collect();
+    let adjacency: HashMap<G::NodeId, HashSet<G::NodeId>> = sigma
+        .iter()
+        .map(|&v| (v, graph.neighbors(v).collect()))
+        .collect();
+
+    for (i, &v) in sigma.iter().enumerate() {
+        let later: Vec<G::NodeId> = adjacency[&v]
+            .iter()
+            .copied()
+            .filter(|w| pos[w] > i)
+            .collect();
+        if later.len() <= 1 {
+            continue;
         }
-        if !y.is_empty() {
-            return false;
+
+        let u = *later.iter().min_by_key(|w| pos[w]).unwrap();
+        let u_neighbors = &adjacency[&u];
+        for &w in &later {
+            if w != u && !u_neighbors.contains(&w) {
+                return None;
+            }
         }
     }
-    true
+
+    Some(sigma)
 }
@@ -0,0 +1,216 @@
+use std::collections::hash_map::Entry::{Occupied, Vacant};
+use std::collections::{BinaryHeap, HashMap, HashSet};
+use std::hash::Hash;
+
+use crate::algo::min_spanning_tree;
+use crate::algo::PositiveMeasure;
+use crate::data::FromElements;
+use crate::graph::Graph;
+use crate::scored::MinScored;
+use crate::visit::{EdgeRef, GraphProp, IntoEdges, VisitMap, Visitable};
+use crate::Undirected;
+
+/// The shortest-path tree rooted at a single terminal: for every reachable
+/// node, its distance from the root and the edge that last improved it.
+struct ShortestPathTree<N, K> {
+    dist: HashMap<N, K>,
+    pred: HashMap<N, N>,
+    pred_cost: HashMap<N, K>,
+}
+
+/// Single-source Dijkstra that additionally records, for every reached
+/// node, the predecessor edge that achieved its shortest distance.
+fn shortest_path_tree<G, F, K>(graph: G, source: G::NodeId, mut edge_cost: F) -> ShortestPathTree<G::NodeId, K>
+where
+    G: IntoEdges + Visitable,
+    G::NodeId: Eq + Hash,
+    F: FnMut(G::EdgeRef) -> K,
+    K: PositiveMeasure + Copy + Ord,
+{
+    let mut dist = HashMap::new();
+    let mut pred = HashMap::new();
+    let mut pred_cost = HashMap::new();
+
+    let zero_score = K::default();
+    dist.insert(source, zero_score);
+
+    let mut visited = graph.visit_map();
+    let mut visit_next = BinaryHeap::new();
+    visit_next.push(MinScored(zero_score, source));
+
+    while let Some(MinScored(node_score, node)) = visit_next.pop() {
+        if visited.is_visited(&node) {
+            continue;
+        }
+        for edge in graph.edges(node) {
+            let next = edge.target();
+            if visited.is_visited(&next) {
+                continue;
+            }
+            let cost = edge_cost(edge);
+            let next_score = node_score + cost;
+            match dist.entry(next) {
+                Occupied(ent) => {
+                    if next_score < *ent.get() {
+                        *ent.into_mut() = next_score;
+                        visit_next.push(MinScored(next_score, next));
+                        pred.insert(next, node);
+                        pred_cost.insert(next, cost);
+                    }
+                }
+                Vacant(ent) => {
+                    ent.insert(next_score);
+                    visit_next.push(MinScored(next_score, next));
+                    pred.insert(next, node);
+                    pred_cost.insert(next, cost);
+                }
+            }
+        }
+        visited.visit(node);
+    }
+
+    ShortestPathTree {
+        dist,
+        pred,
+        pred_cost,
+    }
+}
+
+/// Compute a 2-approximation of the minimum Steiner tree connecting
+/// `terminals` in `graph`.
+///
+/// This is the classic metric-closure construction, reusing the Dijkstra
+/// machinery already present in this module:
+///
+/// 1. Run Dijkstra from every terminal to obtain the shortest-path distance
+///    (and path) to every other terminal.
+/// 2. Build a complete auxiliary graph on the terminals, weighted by those
+///    distances, and take its minimum spanning tree.
+/// 3. Expand each auxiliary edge back into its underlying shortest path in
+///    `graph`, taking the union of all the edges visited.
+/// 4. Take a minimum spanning tree of that union (removing redundant
+///    cycles where paths overlapped) and prune any leaf that is not a
+///    terminal.
+///
+/// Returns the selected edges together with their total weight, or `None`
+/// if the terminals are not all connected to each other (in which case no
+/// tree spanning every terminal exists). Edge costs must be non-negative.
+/// If fewer than two terminals are given, the empty tree is returned.
+///
+/// `graph` must be undirected: the metric closure built in phase 1 treats
+/// `trees[&u].dist[&v]` as the distance between `u` and `v` in either
+/// direction, which only holds when edges are symmetric.
+pub fn steiner_tree<G, F, K>(
+    graph: G,
+    terminals: &[G::NodeId],
+    mut edge_cost: F,
+) -> Option<(Vec<(G::NodeId, G::NodeId)>, K)>
+where
+    G: IntoEdges + Visitable + GraphProp<EdgeType = Undirected>,
+    G::NodeId: Eq + Hash + Ord,
+    F: FnMut(G::EdgeRef) -> K,
+    K: PositiveMeasure + Copy + Ord,
+{
+    if terminals.len() <= 1 {
+        return Some((Vec::new(), K::default()));
+    }
+
+    // Phase 1: metric closure over the terminals.
+    let trees: HashMap<G::NodeId, ShortestPathTree<G::NodeId, K>> = terminals
+        .iter()
+        .map(|&t| (t, shortest_path_tree(graph, t, &mut edge_cost)))
+        .collect();
+
+    // Build the complete auxiliary graph on the terminals.
+    let mut aux: Graph<G::NodeId, K, Undirected> = Graph::with_capacity(terminals.len(), 0);
+    let mut aux_index = HashMap::new();
+    for &t in terminals {
+        aux_index.insert(t, aux.add_node(t));
+    }
+    for (i, &u) in terminals.iter().enumerate() {
+        for &v in &terminals[i + 1..] {
+            if let Some(&d) = trees[&u].dist.get(&v) {
+                aux.add_edge(aux_index[&u], aux_index[&v], d);
+            }
+        }
+    }
+
+    // Phase 2: MST of the auxiliary graph. If the terminals don't all
+    // reach each other, `min_spanning_tree` silently returns a spanning
+    // *forest* instead of a tree; detect that by its edge count rather
+    // than returning a disconnected result as if it were a Steiner tree.
+    let aux_mst: Graph<G::NodeId, K, Undirected> = Graph::from_elements(min_spanning_tree(&aux));
+    if aux_mst.edge_count() != terminals.len() - 1 {
+        return None;
+    }
+
+    // Phase 3: expand each auxiliary edge back into its shortest path, and
+    // union all the edges it passes through.
+    let mut tree_edges: HashSet<(G::NodeId, G::NodeId)> = HashSet::new();
+    let mut edge_weight: HashMap<(G::NodeId, G::NodeId), K> = HashMap::new();
+    for edge in aux_mst.raw_edges() {
+        let u = aux_mst[edge.source()];
+        let v = aux_mst[edge.target()];
+        let path = &trees[&u];
+        let mut node = v;
+        while node != u {
+            let pred = path.pred[&node];
+            let cost = path.pred_cost[&node];
+            let key = if pred <= node { (pred, node) } else { (node, pred) };
+            tree_edges.insert(key);
+            edge_weight.insert(key, cost);
+            node = pred;
+        }
+    }
+
+    // Phase 4: remove redundant cycles where two terminal paths overlapped
+    // by taking an MST of the unioned subgraph.
+    let mut sub: Graph<G::NodeId, K, Undirected> = Graph::new_undirected();
+    let mut sub_index = HashMap::new();
+    for &(u, v) in &tree_edges {
+        let iu = *sub_index.entry(u).or_insert_with(|| sub.add_node(u));
+        let iv = *sub_index.entry(v).or_insert_with(|| sub.add_node(v));
+        sub.add_edge(iu, iv, edge_weight[&(u, v)]);
+    }
+    let sub_mst: Graph<G::NodeId, K, Undirected> = Graph::from_elements(min_spanning_tree(&sub));
+
+    let mut adjacency: HashMap<G::NodeId, Vec<G::NodeId>> = HashMap::new();
+    let mut final_edges: HashSet<(G::NodeId, G::NodeId)> = HashSet::new();
+    for edge in sub_mst.raw_edges() {
+        let u = sub_mst[edge.source()];
+        let v = sub_mst[edge.target()];
+        let key = if u <= v { (u, v) } else { (v, u) };
+        final_edges.insert(key);
+        adjacency.entry(u).or_default().push(v);
+        adjacency.entry(v).or_default().push(u);
+    }
+
+    // Prune leaves that are not terminals.
+    let terminal_set: HashSet<G::NodeId> = terminals.iter().copied().collect();
+    loop {
+        let leaf = adjacency
+            .iter()
+            .find(|(node, neighbors)| neighbors.len() == 1 && !terminal_set.contains(node))
+            .map(|(&node, neighbors)| (node, neighbors[0]));
+        let (leaf, only_neighbor) = match leaf {
+            Some(pair) => pair,
+            None => break,
+        };
+        let key = if leaf <= only_neighbor {
+            (leaf, only_neighbor)
+        } else {
+            (only_neighbor, leaf)
+        };
+        final_edges.remove(&key);
+        adjacency.remove(&leaf);
+        if let Some(neighbors) = adjacency.get_mut(&only_neighbor) {
+            neighbors.retain(|&n| n != leaf);
+        }
+    }
+
+    let total_weight = final_edges
+        .iter()
+        .fold(K::default(), |acc, key| acc + edge_weight[key]);
+
+    Some((final_edges.into_iter().collect(), total_weight))
+}
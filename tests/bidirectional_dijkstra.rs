@@ -1,5 +1,7 @@
 #![cfg(feature = "quickcheck")]
-use petgraph::algo::bidirectional_dijkstra::bidirectional_dijkstra;
+use petgraph::algo::bidirectional_dijkstra::{
+    all_shortest_paths, bidirectional_astar, bidirectional_dijkstra, bidirectional_dijkstra_path,
+};
 use petgraph::algo::dijkstra;
 use petgraph::Graph;
 use quickcheck::{Arbitrary, StdThreadGen};
@@ -27,3 +29,206 @@ fn bidirectional_dijkstra_correctness() {
         }
     }
 }
+
+#[test]
+fn bidirectional_astar_with_zero_heuristic_matches_dijkstra() {
+    let mut gen = StdThreadGen::new(100);
+    let mut graph: Graph<(), u64> = Graph::arbitrary(&mut gen);
+
+    for weight in graph.edge_weights_mut() {
+        if *weight == 0 {
+            *weight = 1;
+        }
+    }
+    let graph = graph;
+
+    let mut nodes = graph.node_indices();
+    let start = nodes.next().unwrap();
+
+    let correct = dijkstra(&graph, start, None, |e| *e.weight());
+    for (goal, res) in correct {
+        if goal != start {
+            let y = bidirectional_astar(
+                &graph,
+                start,
+                goal,
+                |e| *e.weight(),
+                |_| 0,
+                |_| 0,
+            );
+            assert_eq!(y, res);
+        }
+    }
+}
+
+#[test]
+fn bidirectional_astar_with_consistent_nonzero_heuristics() {
+    // A directed graph where the shortest 0 -> 4 path (cost 6) takes the
+    // 0 -> 2 -> 1 -> 4 route, not the direct-looking 0 -> 1 -> 4 route.
+    let mut graph: Graph<(), u64> = Graph::new();
+    let nodes: Vec<_> = (0..5).map(|_| graph.add_node(())).collect();
+    graph.add_edge(nodes[0], nodes[1], 4);
+    graph.add_edge(nodes[0], nodes[2], 1);
+    graph.add_edge(nodes[1], nodes[2], 5);
+    graph.add_edge(nodes[1], nodes[3], 4);
+    graph.add_edge(nodes[1], nodes[4], 3);
+    graph.add_edge(nodes[2], nodes[0], 4);
+    graph.add_edge(nodes[2], nodes[1], 2);
+    graph.add_edge(nodes[3], nodes[1], 3);
+    graph.add_edge(nodes[4], nodes[3], 1);
+
+    let start = nodes[0];
+    let goal = nodes[4];
+
+    let correct = dijkstra(&graph, start, Some(goal), |e| *e.weight());
+
+    // h_forward is the *exact* remaining distance to `goal`. h_backward is
+    // derived from that same potential (h_backward(v) = h_forward(start) -
+    // h_forward(v)), which is what bidirectional_astar requires: the sum
+    // of the two heuristics is pinned at h_forward(start) for every node.
+    let exact_distance_to_goal = |node: petgraph::graph::NodeIndex| -> u64 {
+        match node.index() {
+            0 => 6,
+            1 => 3,
+            2 => 5,
+            3 => 6,
+            4 => 0,
+            _ => unreachable!(),
+        }
+    };
+    let h_forward = exact_distance_to_goal;
+    let h_backward = |node: petgraph::graph::NodeIndex| h_forward(start) - h_forward(node);
+
+    let cost = bidirectional_astar(&graph, start, goal, |e| *e.weight(), h_forward, h_backward);
+    assert_eq!(cost, correct[&goal]);
+}
+
+#[test]
+fn bidirectional_astar_start_equals_goal() {
+    let mut graph: Graph<(), u64> = Graph::new();
+    let a = graph.add_node(());
+    graph.add_node(());
+
+    let cost = bidirectional_astar(&graph, a, a, |e| *e.weight(), |_| 0, |_| 0);
+    assert_eq!(cost, 0);
+}
+
+#[test]
+fn bidirectional_dijkstra_path_correctness() {
+    let mut gen = StdThreadGen::new(100);
+    let mut graph: Graph<(), u64> = Graph::arbitrary(&mut gen);
+
+    for weight in graph.edge_weights_mut() {
+        if *weight == 0 {
+            *weight = 1;
+        }
+    }
+    let graph = graph;
+
+    let mut nodes = graph.node_indices();
+    let start = nodes.next().unwrap();
+
+    let correct = dijkstra(&graph, start, None, |e| *e.weight());
+    for (goal, res) in &correct {
+        if *goal == start {
+            continue;
+        }
+        match bidirectional_dijkstra_path(&graph, start, *goal, |e| *e.weight()) {
+            Some((cost, path)) => {
+                assert_eq!(cost, *res);
+                assert_eq!(path.first(), Some(&start));
+                assert_eq!(path.last(), Some(goal));
+                let path_cost: u64 = path
+                    .windows(2)
+                    .map(|w| {
+                        graph
+                            .edges_connecting(w[0], w[1])
+                            .map(|e| *e.weight())
+                            .min()
+                            .unwrap()
+                    })
+                    .sum();
+                assert_eq!(path_cost, *res);
+            }
+            None => panic!("goal {:?} should be reachable", goal),
+        }
+    }
+}
+
+#[test]
+fn bidirectional_dijkstra_path_start_equals_goal() {
+    let mut graph: Graph<(), u64> = Graph::new();
+    let a = graph.add_node(());
+    graph.add_node(());
+
+    assert_eq!(
+        bidirectional_dijkstra_path(&graph, a, a, |e| *e.weight()),
+        Some((0, vec![a]))
+    );
+}
+
+#[test]
+fn all_shortest_paths_correctness() {
+    let mut gen = StdThreadGen::new(100);
+    let graph: Graph<(), u64> = Graph::arbitrary(&mut gen);
+
+    // Unlike the other tests in this file, zero-weight edges are kept
+    // as-is: they are exactly what lets two different routes to the same
+    // node tie in cost, and that tie-handling is what this function is
+    // actually exercising.
+    let mut nodes = graph.node_indices();
+    let start = nodes.next().unwrap();
+
+    let correct = dijkstra(&graph, start, None, |e| *e.weight());
+    for (goal, res) in correct {
+        if goal == start {
+            continue;
+        }
+        let paths = all_shortest_paths(&graph, start, goal, |e| *e.weight());
+        assert!(!paths.is_empty());
+        for path in &paths {
+            assert_eq!(path.first(), Some(&start));
+            assert_eq!(path.last(), Some(&goal));
+            let cost: u64 = path
+                .windows(2)
+                .map(|w| {
+                    graph
+                        .edges_connecting(w[0], w[1])
+                        .map(|e| *e.weight())
+                        .min()
+                        .unwrap()
+                })
+                .sum();
+            assert_eq!(cost, res);
+        }
+    }
+}
+
+#[test]
+fn all_shortest_paths_is_complete_with_a_zero_weight_tie() {
+    // A -> B (1), A -> G (1), B -> G (0): both A -> G and A -> B -> G cost
+    // 1, so `goal` (G) gets popped off the heap before `B` does, tied at
+    // the same distance. Breaking as soon as `goal` is popped (or
+    // skipping relaxation into already-visited nodes) would silently lose
+    // the A -> B -> G route.
+    let mut graph: Graph<(), u64> = Graph::new();
+    let a = graph.add_node(());
+    let b = graph.add_node(());
+    let g = graph.add_node(());
+    graph.add_edge(a, b, 1);
+    graph.add_edge(a, g, 1);
+    graph.add_edge(b, g, 0);
+
+    let mut paths = all_shortest_paths(&graph, a, g, |e| *e.weight());
+    paths.sort();
+    assert_eq!(paths, vec![vec![a, b, g], vec![a, g]]);
+}
+
+#[test]
+fn all_shortest_paths_start_equals_goal() {
+    let mut graph: Graph<(), u64> = Graph::new();
+    let a = graph.add_node(());
+    graph.add_node(());
+
+    assert_eq!(all_shortest_paths(&graph, a, a, |e| *e.weight()), vec![vec![a]]);
+}
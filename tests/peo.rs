@@ -0,0 +1,75 @@
+use petgraph::algo::peo::{mcs_order, peo};
+use petgraph::graph::UnGraph;
+use std::collections::HashSet;
+
+#[test]
+fn mcs_order_visits_every_node_exactly_once() {
+    let mut graph: UnGraph<(), ()> = UnGraph::new_undirected();
+    let nodes: Vec<_> = (0..4).map(|_| graph.add_node(())).collect();
+    graph.add_edge(nodes[0], nodes[1], ());
+    graph.add_edge(nodes[1], nodes[2], ());
+    graph.add_edge(nodes[2], nodes[3], ());
+    graph.add_edge(nodes[3], nodes[0], ());
+    graph.add_edge(nodes[0], nodes[2], ());
+
+    let order = mcs_order(&graph);
+    assert_eq!(order.len(), nodes.len());
+    assert_eq!(
+        order.iter().copied().collect::<HashSet<_>>(),
+        nodes.iter().copied().collect::<HashSet<_>>()
+    );
+}
+
+#[test]
+fn peo_accepts_a_chordal_graph() {
+    // A 4-cycle with a diagonal (0-2) is chordal: two triangles sharing
+    // the edge 0-2.
+    let mut graph: UnGraph<(), ()> = UnGraph::new_undirected();
+    let nodes: Vec<_> = (0..4).map(|_| graph.add_node(())).collect();
+    graph.add_edge(nodes[0], nodes[1], ());
+    graph.add_edge(nodes[1], nodes[2], ());
+    graph.add_edge(nodes[2], nodes[3], ());
+    graph.add_edge(nodes[3], nodes[0], ());
+    graph.add_edge(nodes[0], nodes[2], ());
+
+    let ordering = peo(&graph).expect("a 4-cycle plus a diagonal is chordal");
+    assert_eq!(
+        ordering.iter().copied().collect::<HashSet<_>>(),
+        nodes.iter().copied().collect::<HashSet<_>>()
+    );
+}
+
+#[test]
+fn peo_rejects_a_chordless_4_cycle() {
+    // A bare 4-cycle has no chord, so it is not chordal.
+    let mut graph: UnGraph<(), ()> = UnGraph::new_undirected();
+    let nodes: Vec<_> = (0..4).map(|_| graph.add_node(())).collect();
+    graph.add_edge(nodes[0], nodes[1], ());
+    graph.add_edge(nodes[1], nodes[2], ());
+    graph.add_edge(nodes[2], nodes[3], ());
+    graph.add_edge(nodes[3], nodes[0], ());
+
+    assert!(peo(&graph).is_none());
+}
+
+#[test]
+fn peo_is_unaffected_by_parallel_edges() {
+    // Same chordal graph as `peo_accepts_a_chordal_graph`, but with the
+    // 0-1 edge duplicated three times over. A parallel edge must not let
+    // a node out-rank another purely by virtue of a fatter multi-edge.
+    let mut graph: UnGraph<(), ()> = UnGraph::new_undirected();
+    let nodes: Vec<_> = (0..4).map(|_| graph.add_node(())).collect();
+    graph.add_edge(nodes[0], nodes[1], ());
+    graph.add_edge(nodes[0], nodes[1], ());
+    graph.add_edge(nodes[0], nodes[1], ());
+    graph.add_edge(nodes[1], nodes[2], ());
+    graph.add_edge(nodes[2], nodes[3], ());
+    graph.add_edge(nodes[3], nodes[0], ());
+    graph.add_edge(nodes[0], nodes[2], ());
+
+    let ordering = peo(&graph).expect("parallel edges must not break chordality detection");
+    assert_eq!(
+        ordering.iter().copied().collect::<HashSet<_>>(),
+        nodes.iter().copied().collect::<HashSet<_>>()
+    );
+}
@@ -0,0 +1,67 @@
+use petgraph::algo::steiner_tree::steiner_tree;
+use petgraph::graph::UnGraph;
+
+#[test]
+fn steiner_tree_on_an_already_minimal_tree_keeps_every_edge() {
+    // 0 - 1 - 2
+    //     |
+    //     3 - 4
+    //     |
+    //     5
+    // All of 0, 2, 4, 5 are terminals; 1 and 3 are Steiner points that must
+    // be kept to connect them, so the whole tree should come back out.
+    let mut graph: UnGraph<(), u64> = UnGraph::new_undirected();
+    let nodes: Vec<_> = (0..6).map(|_| graph.add_node(())).collect();
+    graph.add_edge(nodes[0], nodes[1], 1);
+    graph.add_edge(nodes[1], nodes[2], 1);
+    graph.add_edge(nodes[1], nodes[3], 1);
+    graph.add_edge(nodes[3], nodes[4], 1);
+    graph.add_edge(nodes[3], nodes[5], 1);
+
+    let terminals = [nodes[0], nodes[2], nodes[4], nodes[5]];
+    let (edges, weight) = steiner_tree(&graph, &terminals, |e| *e.weight()).unwrap();
+
+    assert_eq!(weight, 5);
+    assert_eq!(edges.len(), 5);
+    for &(u, v) in &edges {
+        assert!(graph.find_edge(u, v).is_some());
+    }
+}
+
+#[test]
+fn steiner_tree_prunes_a_redundant_detour() {
+    // 0 - 1 - 2 is a direct cheap route between terminals 0 and 2; 0 - 3 -
+    // 4 - 2 is a longer detour through non-terminals that should be
+    // pruned away entirely rather than partially kept.
+    let mut graph: UnGraph<(), u64> = UnGraph::new_undirected();
+    let nodes: Vec<_> = (0..5).map(|_| graph.add_node(())).collect();
+    graph.add_edge(nodes[0], nodes[1], 1);
+    graph.add_edge(nodes[1], nodes[2], 1);
+    graph.add_edge(nodes[0], nodes[3], 5);
+    graph.add_edge(nodes[3], nodes[4], 5);
+    graph.add_edge(nodes[4], nodes[2], 5);
+
+    let terminals = [nodes[0], nodes[2]];
+    let (edges, weight) = steiner_tree(&graph, &terminals, |e| *e.weight()).unwrap();
+
+    assert_eq!(weight, 2);
+    assert_eq!(edges.len(), 2);
+    for &(u, v) in &edges {
+        assert!(graph.find_edge(u, v).is_some());
+    }
+}
+
+#[test]
+fn steiner_tree_returns_none_for_disconnected_terminals() {
+    // Two separate components; a terminal in each can never be spanned by
+    // a single tree.
+    let mut graph: UnGraph<(), u64> = UnGraph::new_undirected();
+    let a = graph.add_node(());
+    let b = graph.add_node(());
+    let c = graph.add_node(());
+    let d = graph.add_node(());
+    graph.add_edge(a, b, 1);
+    graph.add_edge(c, d, 1);
+
+    assert!(steiner_tree(&graph, &[a, c], |e| *e.weight()).is_none());
+}